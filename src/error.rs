@@ -0,0 +1,38 @@
+// Copyright (c) 2026 By David "Hankinsohl" Hankins.
+// This software is licensed under the terms of the MIT License.
+// Created by Hankinsohl on 7/27/2026.
+
+use thiserror::Error;
+
+/// Error type shared by the fs and time modules, so that callers can match on the specific cause
+/// (missing file, line mismatch, malformed JSON, ...) rather than parsing a message string.
+#[derive(Debug, Error)]
+pub enum SlituError {
+    /// An I/O operation on `path` failed.  `action` describes what was being attempted, e.g. "opening" or
+    /// "reading line from", and is folded into the rendered message.
+    #[error("Error '{source}' {action} '{path}'.")]
+    Io {
+        path: String,
+        action: &'static str,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// One file has fewer lines than the other.
+    #[error("'{shorter}' is shorter and contains {lines} lines.")]
+    LengthMismatch { shorter: String, lines: usize },
+
+    /// Two files differ at a given line.
+    #[error("Mismatch at line {line}:\n\t{left_path}: '{left}'\n\t{right_path}: '{right}'")]
+    LineMismatch {
+        line: usize,
+        left_path: String,
+        left: String,
+        right_path: String,
+        right: String,
+    },
+
+    /// Deserializing a value (JSON, RFC 3339, ...) failed.
+    #[error("{0}")]
+    Deserialize(String),
+}
@@ -0,0 +1,823 @@
+// Copyright (c) 2025 By David "Hankinsohl" Hankins.
+// This software is licensed under the terms of the MIT License.
+// Created by Hankinsohl on 3/19/2025.
+
+use super::slash_fmt::SlashFmt;
+use crate::error::SlituError;
+use siphasher::sip128::{Hasher128, SipHasher13};
+use std::fmt;
+use std::fs::File;
+use std::hash::Hasher;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Size in bytes of the prefix hashed by [`files_equal`] before falling back to a full file hash.
+const PARTIAL_HASH_BLOCK_SIZE: usize = 4096;
+
+/// A single difference found while comparing two text files.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiffDetail {
+    /// The two files contain different text at `line`.
+    LineMismatch {
+        line: usize,
+        left: String,
+        right: String,
+    },
+    /// The shorter file contains only `lines` lines.
+    LengthMismatch { shorter: PathBuf, lines: usize },
+}
+
+/// The complete set of differences found by [`compare_text_files_detailed`], gathered in a single pass
+/// rather than stopping at the first mismatch.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComparisonReport {
+    pub path_1: PathBuf,
+    pub path_2: PathBuf,
+    pub details: Vec<DiffDetail>,
+    pub is_error: bool,
+}
+
+impl ComparisonReport {
+    fn new(path_1: PathBuf, path_2: PathBuf) -> Self {
+        Self {
+            path_1,
+            path_2,
+            details: Vec::new(),
+            is_error: false,
+        }
+    }
+
+    fn push(&mut self, detail: DiffDetail) {
+        self.is_error = true;
+        self.details.push(detail);
+    }
+}
+
+impl fmt::Display for ComparisonReport {
+    /// Renders all recorded differences in the same human-readable format previously returned directly as
+    /// an error string by `compare_text_files`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let messages: Vec<String> = self
+            .details
+            .iter()
+            .map(|detail| match detail {
+                DiffDetail::LineMismatch { line, left, right } => format!(
+                    "Mismatch at line {}:\n\t{}: '{}'\n\t{}: '{}'",
+                    line,
+                    self.path_1.to_slash_fmt(),
+                    left,
+                    self.path_2.to_slash_fmt(),
+                    right
+                ),
+                DiffDetail::LengthMismatch { shorter, lines } => format!(
+                    "'{}' is shorter and contains {} lines.",
+                    shorter.to_slash_fmt(),
+                    lines
+                ),
+            })
+            .collect();
+        write!(f, "{}", messages.join("\n"))
+    }
+}
+
+/// Tolerance used to treat numerically-close lines as equal.  A pair of numbers is considered a match if
+/// either the absolute or the relative tolerance is satisfied: `|a - b| <= absolute` or
+/// `|a - b| <= relative * max(|a|, |b|)`.  Leaving a field `None` disables that check.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Tolerance {
+    pub absolute: Option<f64>,
+    pub relative: Option<f64>,
+}
+
+impl Tolerance {
+    fn permits(&self, a: f64, b: f64) -> bool {
+        if let Some(absolute) = self.absolute {
+            if (a - b).abs() <= absolute {
+                return true;
+            }
+        }
+        if let Some(relative) = self.relative {
+            if (a - b).abs() <= relative * a.abs().max(b.abs()) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// A token produced by splitting a line into alternating runs of non-numeric text and numbers.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Text(&'a str),
+    Number(f64),
+}
+
+/// Splits `line` into alternating text/number tokens.  A number run may begin with a single `-` and
+/// includes at most one `.`, e.g. `"x=-1.5,y=2"` tokenizes to `Text("x="), Number(-1.5), Text(",y="),
+/// Number(2.0)`.
+fn tokenize(line: &str) -> Vec<Token<'_>> {
+    let bytes = line.as_bytes();
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        let is_number_start = c.is_ascii_digit()
+            || (c == '-'
+                && bytes
+                    .get(i + 1)
+                    .is_some_and(|next| (*next as char).is_ascii_digit()));
+        if !is_number_start {
+            i += 1;
+            continue;
+        }
+
+        if i > text_start {
+            tokens.push(Token::Text(&line[text_start..i]));
+        }
+        let start = i;
+        if c == '-' {
+            i += 1;
+        }
+        while i < bytes.len() && (bytes[i] as char).is_ascii_digit() {
+            i += 1;
+        }
+        if i < bytes.len()
+            && bytes[i] as char == '.'
+            && bytes
+                .get(i + 1)
+                .is_some_and(|next| (*next as char).is_ascii_digit())
+        {
+            i += 1;
+            while i < bytes.len() && (bytes[i] as char).is_ascii_digit() {
+                i += 1;
+            }
+        }
+        let number = &line[start..i];
+        match number.parse::<f64>() {
+            Ok(value) => tokens.push(Token::Number(value)),
+            Err(_) => tokens.push(Token::Text(number)),
+        }
+        text_start = i;
+    }
+    if text_start < bytes.len() {
+        tokens.push(Token::Text(&line[text_start..]));
+    }
+    tokens
+}
+
+/// Returns true if `l1` and `l2` have the same non-numeric structure and every numeric token pair falls
+/// within `tolerance`.
+fn lines_match_within_tolerance(l1: &str, l2: &str, tolerance: &Tolerance) -> bool {
+    let tokens_1 = tokenize(l1);
+    let tokens_2 = tokenize(l2);
+    if tokens_1.len() != tokens_2.len() {
+        return false;
+    }
+    tokens_1.iter().zip(tokens_2.iter()).all(|pair| match pair {
+        (Token::Text(a), Token::Text(b)) => a == b,
+        (Token::Number(a), Token::Number(b)) => a == b || tolerance.permits(*a, *b),
+        _ => false,
+    })
+}
+
+/// Selects how [`compare_text_files_with_mode`] and [`compare_text_files_detailed_with_mode`] decide
+/// whether a differing line should still count as a match.
+#[derive(Clone, Copy, Debug)]
+pub enum CompareMode<'a> {
+    /// Lines are compared for exact equality.
+    Exact,
+    /// A line is skipped if either side contains one of the given substrings, as with the legacy
+    /// `filters` parameter.
+    SkipContaining(&'a [&'a str]),
+    /// A line is skipped if either side is matched by one of the given `[..]` wildcard patterns; see
+    /// [`line_matches`].
+    PatternMatch(&'a [&'a str]),
+}
+
+/// Returns true if `actual` matches `pattern`, where `pattern` may contain `[..]` to mean "any sequence
+/// of characters", following cargo's test-support `lines_match` convention.  The first and last literal
+/// segments are anchored to the start and end of `actual` respectively; segments in between only need to
+/// appear in order.
+pub fn line_matches(pattern: &str, actual: &str) -> bool {
+    let segments: Vec<&str> = pattern.split("[..]").collect();
+    let last = segments.len() - 1;
+    let mut remaining = actual;
+    for (i, segment) in segments.iter().enumerate() {
+        if i == last && i > 0 {
+            return remaining.ends_with(segment);
+        }
+        match remaining.find(segment) {
+            Some(j) => {
+                if i == 0 && j != 0 {
+                    return false;
+                }
+                remaining = &remaining[j + segment.len()..];
+            }
+            None => return false,
+        }
+    }
+    remaining.is_empty()
+}
+
+fn skip_line(mode: &CompareMode<'_>, l1: &str, l2: &str) -> bool {
+    match mode {
+        CompareMode::Exact => false,
+        CompareMode::SkipContaining(filters) => filters
+            .iter()
+            .any(|filter| l1.contains(filter) || l2.contains(filter)),
+        CompareMode::PatternMatch(patterns) => patterns
+            .iter()
+            .any(|pattern| line_matches(pattern, l1) || line_matches(pattern, l2)),
+    }
+}
+
+/// Builds the [`SlituError`] that [`compare_text_files`] and [`compare_text_files_with_mode`] return for
+/// the first mismatch recorded in `report`, mirroring the stop-at-first-difference behavior they had
+/// before [`compare_text_files_detailed`] was introduced.
+fn first_mismatch_error(report: &ComparisonReport) -> SlituError {
+    match report
+        .details
+        .first()
+        .expect("is_error implies at least one detail was recorded")
+    {
+        DiffDetail::LineMismatch { line, left, right } => SlituError::LineMismatch {
+            line: *line,
+            left_path: report.path_1.to_slash_fmt(),
+            left: left.clone(),
+            right_path: report.path_2.to_slash_fmt(),
+            right: right.clone(),
+        },
+        DiffDetail::LengthMismatch { shorter, lines } => SlituError::LengthMismatch {
+            shorter: shorter.to_slash_fmt(),
+            lines: *lines,
+        },
+    }
+}
+
+/// Compares two text files.  If the files are identical, Ok(()) is returned; otherwise a descriptive error
+/// is returned as an Err result.
+pub fn compare_text_files<P: AsRef<Path>>(
+    p1: P,
+    p2: P,
+    filters: Option<&[&str]>,
+) -> Result<(), SlituError> {
+    let report = compare_text_files_detailed(p1, p2, filters)?;
+    if report.is_error {
+        Err(first_mismatch_error(&report))
+    } else {
+        Ok(())
+    }
+}
+
+/// Compares two text files line by line, collecting every mismatch into a [`ComparisonReport`] instead of
+/// stopping at the first one.  This lets callers see the complete set of deltas in a single pass rather than
+/// re-running repeatedly after each fix.  `filters` behaves as in [`compare_text_files`]: lines containing any
+/// of the given substrings are skipped.
+pub fn compare_text_files_detailed<P: AsRef<Path>>(
+    p1: P,
+    p2: P,
+    filters: Option<&[&str]>,
+) -> Result<ComparisonReport, SlituError> {
+    let mode = match filters {
+        Some(filters) => CompareMode::SkipContaining(filters),
+        None => CompareMode::Exact,
+    };
+    compare_text_files_detailed_impl(p1, p2, &mode, None)
+}
+
+/// Like [`compare_text_files_detailed`], but lines that differ only in numeric tokens within `tolerance`
+/// are treated as equal.  See [`Tolerance`] for the matching rule.
+pub fn compare_text_files_detailed_with_tolerance<P: AsRef<Path>>(
+    p1: P,
+    p2: P,
+    filters: Option<&[&str]>,
+    tolerance: Tolerance,
+) -> Result<ComparisonReport, SlituError> {
+    let mode = match filters {
+        Some(filters) => CompareMode::SkipContaining(filters),
+        None => CompareMode::Exact,
+    };
+    compare_text_files_detailed_impl(p1, p2, &mode, Some(&tolerance))
+}
+
+/// Like [`compare_text_files`], but using a [`CompareMode`] to decide which differing lines to skip,
+/// including [`CompareMode::PatternMatch`] for fixtures whose expected output embeds `[..]` wildcards.
+pub fn compare_text_files_with_mode<P: AsRef<Path>>(
+    p1: P,
+    p2: P,
+    mode: CompareMode<'_>,
+) -> Result<(), SlituError> {
+    let report = compare_text_files_detailed_with_mode(p1, p2, mode)?;
+    if report.is_error {
+        Err(first_mismatch_error(&report))
+    } else {
+        Ok(())
+    }
+}
+
+/// Like [`compare_text_files_detailed`], but using a [`CompareMode`] to decide which differing lines to
+/// skip.
+pub fn compare_text_files_detailed_with_mode<P: AsRef<Path>>(
+    p1: P,
+    p2: P,
+    mode: CompareMode<'_>,
+) -> Result<ComparisonReport, SlituError> {
+    compare_text_files_detailed_impl(p1, p2, &mode, None)
+}
+
+fn compare_text_files_detailed_impl<P: AsRef<Path>>(
+    p1: P,
+    p2: P,
+    mode: &CompareMode<'_>,
+    tolerance: Option<&Tolerance>,
+) -> Result<ComparisonReport, SlituError> {
+    let path_1 = p1.as_ref().to_path_buf();
+    let path_2 = p2.as_ref().to_path_buf();
+    let f1 = File::open(&path_1).map_err(|err| SlituError::Io {
+        path: path_1.to_slash_fmt(),
+        action: "opening",
+        source: err,
+    })?;
+    let f2 = File::open(&path_2).map_err(|err| SlituError::Io {
+        path: path_2.to_slash_fmt(),
+        action: "opening",
+        source: err,
+    })?;
+    let r1 = BufReader::new(f1);
+    let r2 = BufReader::new(f2);
+
+    let mut report = ComparisonReport::new(path_1.clone(), path_2.clone());
+    let mut line_number = 0;
+    let mut lines_2 = r2.lines();
+    for l1 in r1.lines() {
+        line_number += 1;
+        let l1 = l1.map_err(|err| SlituError::Io {
+            path: path_1.to_slash_fmt(),
+            action: "reading line from",
+            source: err,
+        })?;
+        let l2 = match lines_2.next() {
+            Some(l2) => l2.map_err(|err| SlituError::Io {
+                path: path_2.to_slash_fmt(),
+                action: "reading line from",
+                source: err,
+            })?,
+            None => {
+                report.push(DiffDetail::LengthMismatch {
+                    shorter: path_2.clone(),
+                    lines: line_number - 1,
+                });
+                return Ok(report);
+            }
+        };
+
+        if skip_line(mode, &l1, &l2) {
+            continue;
+        }
+
+        if l1 != l2 {
+            let within_tolerance = tolerance
+                .is_some_and(|tolerance| lines_match_within_tolerance(&l1, &l2, tolerance));
+            if !within_tolerance {
+                report.push(DiffDetail::LineMismatch {
+                    line: line_number,
+                    left: l1,
+                    right: l2,
+                });
+            }
+        }
+    }
+
+    if lines_2.next().is_some() {
+        report.push(DiffDetail::LengthMismatch {
+            shorter: path_1.clone(),
+            lines: line_number,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Returns true if the files at `a` and `b` are byte-for-byte identical.  Unlike [`compare_text_files`],
+/// this works on binary as well as text input: file lengths are compared first, then a partial hash of the
+/// first [`PARTIAL_HASH_BLOCK_SIZE`] bytes, and only if those collide a full streaming hash of each file.
+/// This short-circuits cheaply for the common "different size / different prefix" cases while still being
+/// byte-accurate.
+pub fn files_equal<P: AsRef<Path>>(a: P, b: P) -> Result<bool, SlituError> {
+    let path_a = a.as_ref();
+    let path_b = b.as_ref();
+
+    let len_a = std::fs::metadata(path_a)
+        .map_err(|err| SlituError::Io {
+            path: path_a.to_slash_fmt(),
+            action: "opening",
+            source: err,
+        })?
+        .len();
+    let len_b = std::fs::metadata(path_b)
+        .map_err(|err| SlituError::Io {
+            path: path_b.to_slash_fmt(),
+            action: "opening",
+            source: err,
+        })?
+        .len();
+    if len_a != len_b {
+        return Ok(false);
+    }
+
+    let mut file_a = File::open(path_a).map_err(|err| SlituError::Io {
+        path: path_a.to_slash_fmt(),
+        action: "opening",
+        source: err,
+    })?;
+    let mut file_b = File::open(path_b).map_err(|err| SlituError::Io {
+        path: path_b.to_slash_fmt(),
+        action: "opening",
+        source: err,
+    })?;
+
+    if hash_prefix(&mut file_a, path_a, len_a)? != hash_prefix(&mut file_b, path_b, len_a)? {
+        return Ok(false);
+    }
+
+    Ok(hash_full(&mut file_a, path_a)? == hash_full(&mut file_b, path_b)?)
+}
+
+/// Hashes the first `len.min(PARTIAL_HASH_BLOCK_SIZE)` bytes of `file` using a fast 128-bit SipHash.
+fn hash_prefix(file: &mut File, path: &Path, len: u64) -> Result<u128, SlituError> {
+    let mut buffer = vec![0u8; PARTIAL_HASH_BLOCK_SIZE.min(len as usize)];
+    file.read_exact(&mut buffer).map_err(|err| SlituError::Io {
+        path: path.to_slash_fmt(),
+        action: "reading",
+        source: err,
+    })?;
+    let mut hasher = SipHasher13::new();
+    hasher.write(&buffer);
+    Ok(hasher.finish128().as_u128())
+}
+
+/// Hashes the entire contents of `file` using a streaming 128-bit SipHash.
+fn hash_full(file: &mut File, path: &Path) -> Result<u128, SlituError> {
+    file.seek(SeekFrom::Start(0))
+        .map_err(|err| SlituError::Io {
+            path: path.to_slash_fmt(),
+            action: "reading",
+            source: err,
+        })?;
+    let mut hasher = SipHasher13::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        let read = file.read(&mut buffer).map_err(|err| SlituError::Io {
+            path: path.to_slash_fmt(),
+            action: "reading",
+            source: err,
+        })?;
+        if read == 0 {
+            break;
+        }
+        hasher.write(&buffer[..read]);
+    }
+    Ok(hasher.finish128().as_u128())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn path_to(file_name: &str) -> PathBuf {
+        Path::new("tests/assets").join(file_name)
+    }
+
+    #[test]
+    fn compare_text_files_same_file_used_twice_generates_no_errors() {
+        let result = compare_text_files(&path_to("file.json"), &path_to("file.json"), None);
+        assert!(result.is_ok(), "{}", result.unwrap_err());
+    }
+
+    #[test]
+    fn compare_text_files_identical_files_generate_no_errors() {
+        let result = compare_text_files(
+            &path_to("file.json"),
+            &path_to("exact_copy_of_file.json"),
+            None,
+        );
+        assert!(result.is_ok(), "{}", result.unwrap_err());
+    }
+
+    #[test]
+    fn compare_text_files_file_1_longer_generates_error() {
+        let result = compare_text_files(&path_to("longer.json"), &path_to("file.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 1 longer failed to generate error."
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "'tests/assets/file.json' is shorter and contains 11 lines."
+        );
+    }
+
+    #[test]
+    fn compare_text_files_file_2_longer_generates_error() {
+        let result = compare_text_files(&path_to("file.json"), &path_to("longer.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 2 longer failed to generate error."
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "'tests/assets/file.json' is shorter and contains 11 lines."
+        );
+    }
+
+    #[test]
+    fn compare_text_files_file_1_shorter_generates_error() {
+        let result = compare_text_files(&path_to("shorter.json"), &path_to("file.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 1 shorter failed to generate error."
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "'tests/assets/shorter.json' is shorter and contains 8 lines."
+        );
+    }
+
+    #[test]
+    fn compare_text_files_file_2_shorter_generates_error() {
+        let result = compare_text_files(&path_to("file.json"), &path_to("shorter.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 2 shorter failed to generate error."
+        );
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "'tests/assets/shorter.json' is shorter and contains 8 lines."
+        );
+    }
+
+    #[test]
+    fn compare_text_files_with_file_1_missing_generates_error() {
+        let result = compare_text_files(&path_to("missing.json"), &path_to("file.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 1 missing failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("opening 'tests/assets/missing.json'"));
+    }
+
+    #[test]
+    fn compare_text_files_with_file_2_missing_generates_error() {
+        let result = compare_text_files(&path_to("file.json"), &path_to("missing.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 2 missing failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("opening 'tests/assets/missing.json'"));
+    }
+
+    #[test]
+    fn compare_text_files_with_file_1_binary_generates_error() {
+        let result = compare_text_files(&path_to("binary_file.bin"), &path_to("file.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 1 binary failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("reading line from 'tests/assets/binary_file.bin'"));
+    }
+
+    #[test]
+    fn compare_text_files_with_file_2_binary_generates_error() {
+        let result = compare_text_files(&path_to("file.json"), &path_to("binary_file.bin"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 2 binary failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("reading line from 'tests/assets/binary_file.bin'"));
+    }
+
+    #[test]
+    fn compare_text_files_with_file_1_dissimilar_generates_error() {
+        let result = compare_text_files(&path_to("dissimilar.json"), &path_to("file.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 1 dissimilar failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Mismatch at line 3"));
+    }
+
+    #[test]
+    fn compare_text_files_with_file_2_dissimilar_generates_error() {
+        let result = compare_text_files(&path_to("file.json"), &path_to("dissimilar.json"), None);
+        assert!(
+            !result.is_ok(),
+            "Comparison with file 1 dissimilar failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Mismatch at line 3"));
+    }
+
+    #[test]
+    fn compare_dissimilar_ids_text_files_without_filter_to_skip_ids_generates_error() {
+        let result = compare_text_files(
+            &path_to("file_2.json"),
+            &path_to("dissimilar_ids_2.json"),
+            None,
+        );
+        assert!(
+            !result.is_ok(),
+            "Comparison of files with dissimilar ids without filter to skip ids failed to generate error."
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Mismatch at line 3"));
+    }
+
+    #[test]
+    fn compare_dissimilar_ids_text_files_using_filter_to_skip_ids_generates_no_error() {
+        let result = compare_text_files(
+            &path_to("file_2.json"),
+            &path_to("dissimilar_ids_2.json"),
+            Some(&["_id"]),
+        );
+        assert!(result.is_ok(), "{}", result.unwrap_err());
+    }
+
+    #[test]
+    fn compare_text_files_detailed_collects_all_mismatches() {
+        let report = compare_text_files_detailed(
+            &path_to("multiple_mismatches.json"),
+            &path_to("file.json"),
+            None,
+        )
+        .unwrap();
+        assert!(report.is_error);
+        assert!(
+            report
+                .details
+                .iter()
+                .filter(|detail| matches!(detail, DiffDetail::LineMismatch { .. }))
+                .count()
+                > 1
+        );
+    }
+
+    #[test]
+    fn compare_text_files_detailed_identical_files_generate_no_errors() {
+        let report =
+            compare_text_files_detailed(&path_to("file.json"), &path_to("file.json"), None)
+                .unwrap();
+        assert!(!report.is_error);
+        assert!(report.details.is_empty());
+    }
+
+    #[test]
+    fn lines_match_within_tolerance_accepts_small_rounding_differences() {
+        let tolerance = Tolerance {
+            absolute: Some(0.01),
+            relative: None,
+        };
+        assert!(lines_match_within_tolerance(
+            "temp=20.001,id=7",
+            "temp=20.002,id=7",
+            &tolerance
+        ));
+    }
+
+    #[test]
+    fn lines_match_within_tolerance_rejects_differing_text() {
+        let tolerance = Tolerance {
+            absolute: Some(1.0),
+            relative: None,
+        };
+        assert!(!lines_match_within_tolerance(
+            "temp=20.0,id=7",
+            "temp=20.5,ix=7",
+            &tolerance
+        ));
+    }
+
+    #[test]
+    fn lines_match_within_tolerance_rejects_values_outside_tolerance() {
+        let tolerance = Tolerance {
+            absolute: Some(0.01),
+            relative: None,
+        };
+        assert!(!lines_match_within_tolerance(
+            "temp=20.0",
+            "temp=20.5",
+            &tolerance
+        ));
+    }
+
+    #[test]
+    fn compare_text_files_detailed_with_tolerance_identical_files_generate_no_errors() {
+        let report = compare_text_files_detailed_with_tolerance(
+            &path_to("file.json"),
+            &path_to("file.json"),
+            None,
+            Tolerance {
+                absolute: Some(0.01),
+                relative: None,
+            },
+        )
+        .unwrap();
+        assert!(!report.is_error, "{}", report);
+    }
+
+    #[test]
+    fn line_matches_handles_leading_trailing_and_middle_wildcards() {
+        assert!(line_matches("[..]", "anything"));
+        assert!(line_matches("id=[..]", "id=42"));
+        assert!(line_matches("[..]=42", "id=42"));
+        assert!(line_matches("id=[..],status=ok", "id=42,status=ok"));
+        assert!(!line_matches("id=[..],status=ok", "id=42,status=failed"));
+    }
+
+    #[test]
+    fn line_matches_without_wildcard_requires_exact_equality() {
+        assert!(line_matches("id=42", "id=42"));
+        assert!(!line_matches("id=42", "id=43"));
+        assert!(!line_matches("id=42", "id=42,extra"));
+    }
+
+    #[test]
+    fn compare_text_files_detailed_with_mode_pattern_match_skips_wildcard_lines() {
+        let report = compare_text_files_detailed_with_mode(
+            &path_to("file_2.json"),
+            &path_to("dissimilar_ids_2.json"),
+            CompareMode::PatternMatch(&["\"_id\": [..]"]),
+        )
+        .unwrap();
+        assert!(!report.is_error, "{}", report);
+    }
+
+    #[test]
+    fn compare_text_files_with_mode_exact_reports_mismatch() {
+        let result = compare_text_files_with_mode(
+            &path_to("file.json"),
+            &path_to("dissimilar.json"),
+            CompareMode::Exact,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn files_equal_same_file_used_twice_returns_true() {
+        let result = files_equal(&path_to("file.json"), &path_to("file.json"));
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn files_equal_identical_files_returns_true() {
+        let result = files_equal(&path_to("file.json"), &path_to("exact_copy_of_file.json"));
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn files_equal_different_length_returns_false() {
+        let result = files_equal(&path_to("file.json"), &path_to("longer.json"));
+        assert!(!result.unwrap());
+    }
+
+    #[test]
+    fn files_equal_same_length_different_content_returns_false() {
+        let result = files_equal(&path_to("file.json"), &path_to("dissimilar.json"));
+        assert!(!result.unwrap());
+    }
+
+    #[test]
+    fn files_equal_binary_files_are_compared_byte_for_byte() {
+        let result = files_equal(&path_to("binary_file.bin"), &path_to("binary_file.bin"));
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn files_equal_with_missing_file_generates_error() {
+        let result = files_equal(&path_to("missing.json"), &path_to("file.json"));
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("opening 'tests/assets/missing.json'"));
+    }
+}
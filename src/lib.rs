@@ -2,12 +2,15 @@
 // This software is licensed under the terms of the MIT License.
 // Created by Hankinsohl on 3/18/2025.
 
+pub use error::SlituError;
+
 #[cfg(feature = "fs")]
 pub use fs::{compare::compare_text_files, slash_fmt::SlashFmt};
 
 #[cfg(feature = "time")]
 pub use time::timestamp::Timestamp;
 
+pub mod error;
 #[cfg(feature = "fs")]
 pub mod fs;
 #[cfg(feature = "time")]
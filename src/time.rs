@@ -0,0 +1,5 @@
+// Copyright (c) 2025 By David "Hankinsohl" Hankins.
+// This software is licensed under the terms of the MIT License.
+// Created by Hankinsohl on 3/19/2025.
+
+pub mod timestamp;
@@ -2,19 +2,26 @@
 // This software is licensed under the terms of the MIT License.
 // Created by Hankinsohl on 2/24/2026.
 
-use anyhow::{Error, Result};
+use crate::error::SlituError;
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fs::File;
+use std::hash::{Hash, Hasher};
 use std::io::{BufReader, Read};
 use std::path::Path;
 
 /// Timestamp is a struct used to record and compare times.  Timestamp is based on UTC and is thus suitable for
 /// comparing times obtained from different computers.  Timestamp uses serde to serialize/deserialize to/from
-/// JSON.
-#[derive(Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+/// JSON.  When created from a source that carries its own UTC offset (e.g. [`Timestamp::from_rfc3339`]), that
+/// offset is preserved across a serde round-trip and used by [`Timestamp::to_rfc3339`]; comparison and
+/// ordering, however, always compare the underlying UTC instant, so two Timestamps captured in different
+/// zones still compare correctly.
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Timestamp {
     time: DateTime<Utc>,
+    #[serde(default)]
+    offset_seconds: Option<i32>,
 }
 
 impl AsRef<DateTime<Utc>> for Timestamp {
@@ -28,6 +35,32 @@ impl Default for Timestamp {
     }
 }
 
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.time == other.time
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.time.cmp(&other.time)
+    }
+}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.time.hash(state);
+    }
+}
+
 impl Timestamp {
     /// Creates a Timestamp for the current time.
     pub fn new() -> Self {
@@ -38,25 +71,67 @@ impl Timestamp {
     pub fn create(time: DateTime<Utc>) -> Self {
         Self {
             time,
+            offset_seconds: None,
         }
     }
 
     /// Creates a Timestamp using JSON stored in path.
-    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
-        let file = File::open(path.as_ref())?;
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, SlituError> {
+        let file = File::open(path.as_ref()).map_err(|err| SlituError::Io {
+            path: path.as_ref().to_string_lossy().into_owned(),
+            action: "opening",
+            source: err,
+        })?;
         let mut reader = BufReader::new(file);
         Timestamp::from_reader(&mut reader)
     }
 
     /// Creates a Timestamp using JSON read from reader.
-    pub fn from_reader(reader: &mut dyn Read) -> Result<Self, Error> {
-        Ok(serde_json::from_reader(reader)?)
+    pub fn from_reader(reader: &mut dyn Read) -> Result<Self, SlituError> {
+        serde_json::from_reader(reader).map_err(|err| SlituError::Deserialize(err.to_string()))
+    }
+
+    /// Creates a Timestamp by parsing an ISO-8601 / RFC 3339 string, remembering the offset it was
+    /// expressed in so that [`Timestamp::to_rfc3339`] renders it back in the same zone.
+    pub fn from_rfc3339(s: &str) -> Result<Self, SlituError> {
+        let parsed = DateTime::parse_from_rfc3339(s)
+            .map_err(|err| SlituError::Deserialize(err.to_string()))?;
+        Ok(Self {
+            time: parsed.with_timezone(&Utc),
+            offset_seconds: Some(parsed.offset().local_minus_utc()),
+        })
+    }
+
+    /// Renders this Timestamp as an RFC 3339 string, using the originating offset if one was captured,
+    /// or UTC otherwise.
+    pub fn to_rfc3339(&self) -> String {
+        match self.offset_seconds {
+            Some(offset_seconds) => {
+                let offset = FixedOffset::east_opt(offset_seconds).unwrap_or_else(|| {
+                    FixedOffset::east_opt(0).expect("0 is always a valid FixedOffset")
+                });
+                self.time.with_timezone(&offset).to_rfc3339()
+            }
+            None => self.time.to_rfc3339(),
+        }
     }
 
     /// Returns true if this Timestamp is as new or newer than other.
     pub fn is_current(&self, other: &Timestamp) -> bool {
         self.time >= other.time
     }
+
+    /// Returns the duration elapsed between `other` and this Timestamp; positive if this Timestamp is
+    /// later than `other`.
+    pub fn elapsed_since(&self, other: &Timestamp) -> chrono::Duration {
+        self.time.signed_duration_since(other.time)
+    }
+
+    /// Returns the duration elapsed between this Timestamp and now; positive if this Timestamp is in the
+    /// past.
+    pub fn age(&self) -> chrono::Duration {
+        Utc::now().signed_duration_since(self.time)
+    }
 }
 
 #[cfg(test)]
@@ -101,4 +176,33 @@ mod tests {
         assert!(ts2_deserialized.is_current(&ts1_deserialized));
         assert!(!ts1_deserialized.is_current(&ts2_deserialized));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_timestamp_elapsed_and_age() {
+        let ts1 = Timestamp::new();
+        thread::sleep(Duration::from_millis(5));
+        let ts2 = Timestamp::new();
+
+        assert!(ts2.elapsed_since(&ts1) >= chrono::Duration::zero());
+        assert!(ts1.elapsed_since(&ts2) <= chrono::Duration::zero());
+        assert!(ts1.age() >= chrono::Duration::zero());
+    }
+
+    #[test]
+    fn test_timestamp_from_rfc3339_round_trips_offset() {
+        let ts = Timestamp::from_rfc3339("2026-02-24T10:15:00+02:00").unwrap();
+        assert_eq!(ts.to_rfc3339(), "2026-02-24T10:15:00+02:00");
+
+        let json = serde_json::to_string(&ts).unwrap();
+        let deserialized: Timestamp = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.to_rfc3339(), "2026-02-24T10:15:00+02:00");
+        assert_eq!(ts, deserialized);
+    }
+
+    #[test]
+    fn test_timestamp_from_rfc3339_compares_across_offsets() {
+        let utc = Timestamp::from_rfc3339("2026-02-24T08:15:00+00:00").unwrap();
+        let plus_two = Timestamp::from_rfc3339("2026-02-24T10:15:00+02:00").unwrap();
+        assert_eq!(utc, plus_two);
+    }
+}